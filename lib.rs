@@ -41,22 +41,16 @@ limitations under the License.
 //!    layer_count: u16,
 //!}
 //!fn main() {
-//!    let mut iterator = [NamedField::<WaffleInfo> {
-//!        name: String::from("butter_amount"),
-//!       wrapped_value: WaffleInfo::Topping(44),
-//!    }, NamedField::<WaffleInfo> {
-//!        name: String::from("layer_count"),
-//!        wrapped_value: WaffleInfo::Layers(444),
-//!    }, NamedField::<WaffleInfo> {
-//!        name: String::from("syrup_amount"),
-//!        wrapped_value: WaffleInfo::Topping(4),
-//!    }].into_iter();
+//!    let mut iterator = [NamedField::new(String::from("butter_amount"), WaffleInfo::Topping(44)),
+//!    NamedField::new(String::from("layer_count"), WaffleInfo::Layers(444)),
+//!    NamedField::new(String::from("syrup_amount"), WaffleInfo::Topping(4))].into_iter();
 //!    let generated_struct = Waffles::create_struct(&mut iterator).unwrap();
 //!    assert_eq!(4,generated_struct.syrup_amount);
 //!    assert_eq!(44,generated_struct.butter_amount);
 //!    assert_eq!(444,generated_struct.layer_count);
 //!}
 //!```
+use std::collections::BTreeMap;
 /// [`SpecifyCreatableStruct`]'s original intended use case was with user-defined [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html)s, and this structure was designed for convenience, allowing the implementor to store both a [`String`]ification of the field's name,
 /// and the field's value. Note that it is also the type that the argument passed to [`create_struct`](SpecifyCreatableStruct::create_struct) must iterate over.
 pub struct NamedField<I> {
@@ -64,13 +58,84 @@ pub struct NamedField<I> {
     pub name: String,
     ///Intended to hold the value to be assigned to a given field in the target [`struct`](https://doc.rust-lang.org/1.58.1/std/keyword.struct.html)
     pub wrapped_value: I,
+    ///Disambiguates fields that share a `name`: a field's identity is the combination of `name` *and* `labels`, not `name` alone. Defaults to empty for fields that don't need disambiguating.
+    pub labels: BTreeMap<String, String>,
+}
+impl<I> NamedField<I> {
+    ///Builds a [`NamedField`] with no `labels`, for the common case where `name` alone identifies the field.
+    pub fn new(name: String, wrapped_value: I) -> Self {
+        NamedField {
+            name,
+            wrapped_value,
+            labels: BTreeMap::new(),
+        }
+    }
+}
+///Describes what went wrong when a single field could not be populated by [`try_create_struct`](SpecifyCreatableStruct::try_create_struct).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldErrorKind {
+    ///No [`NamedField`] matching this field's name was found in the seed iterator
+    Missing,
+    ///More than one [`NamedField`] matching this field's name was found in the seed iterator
+    Duplicate,
+    ///A [`NamedField`] matching this field's name was found, but its `wrapped_value` did not hold the expected variant
+    TypeMismatch {
+        ///The name of the variant of [`InnerIteratorType`](SpecifyCreatableStruct::InnerIteratorType) this field expected
+        expected: &'static str,
+        ///The name of the variant of [`InnerIteratorType`](SpecifyCreatableStruct::InnerIteratorType) this field actually received
+        found: &'static str,
+    },
+}
+///Names a single field that [`try_create_struct`](SpecifyCreatableStruct::try_create_struct) could not populate, and says why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    ///The name of the field that could not be populated
+    pub name: String,
+    ///What went wrong when attempting to populate this field
+    pub kind: FieldErrorKind,
+}
+///Describes a single field's identity for the purposes of alias resolution and default-value fallback during construction. Populated by the derive macro from the field's `rename`/`default` attributes, and consulted by [`try_create_struct`](SpecifyCreatableStruct::try_create_struct).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSpec {
+    ///The field's canonical name, as it appears on the target struct
+    pub canonical: &'static str,
+    ///Alternate names a [`NamedField`] may carry that should still resolve to this field
+    pub aliases: &'static [&'static str],
+    ///Whether this field falls back to a default value rather than producing a [`FieldErrorKind::Missing`] error when no matching [`NamedField`] is found in the seed iterator
+    pub has_default: bool,
 }
 ///Any type implementing this trait must be convertable from an [`Iterator`] over elements of type [`NamedField<I>`], where `I` is the same type assigned to [`InnerIteratorType`](SpecifyCreatableStruct::InnerIteratorType).
 pub trait SpecifyCreatableStruct: Sized {
     ///The type contained in [`NamedField`]
     type InnerIteratorType;
     ///The type that should be returned if the conversion fails
-    type Error;
+    type Error: From<FieldError>;
+    ///Describes each field's canonical name, accepted aliases, and whether it falls back to a default when absent from the seed iterator. The derive macro populates this; hand-written implementations may return an empty slice to opt out of alias/default resolution.
+    fn field_spec() -> &'static [FieldSpec] {
+        &[]
+    }
+    ///Returns the discriminant tag that `field_name`'s `InnerIteratorType` value is expected to carry, or `None` if the field places no such constraint. The derive macro populates this from `enum_unwrapper`'s discriminants, and [`try_create_struct`](SpecifyCreatableStruct::try_create_struct) implementations should compare it against the [`std::mem::discriminant`] of an incoming [`NamedField`]'s `wrapped_value` before assigning it, reporting a [`FieldErrorKind::TypeMismatch`] on a mismatch instead of relying solely on a failed conversion.
+    fn expected_tag(field_name: &str) -> Option<u64> {
+        let _ = field_name;
+        None
+    }
     /// The function that should be called to attempt a conversion from an [`Iterator`] to the type implementing this trait.
-    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self,Self::Error>;
+    ///
+    /// The default implementation defers to [`try_create_struct`](SpecifyCreatableStruct::try_create_struct) and, on failure, reports only the first of the collected [`FieldError`]s.
+    fn create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self,Self::Error> {
+        Self::try_create_struct(seed_iterator).map_err(|mut errors| errors.remove(0).into())
+    }
+    ///Attempts a conversion from an [`Iterator`] to the type implementing this trait, draining the entire iterator and collecting every [`FieldError`] encountered along the way, rather than stopping at the first one. Implementors should consult [`field_spec`](SpecifyCreatableStruct::field_spec) so that a missing-but-defaulted field is filled in rather than reported, and an aliased name is resolved to its canonical field.
+    fn try_create_struct(seed_iterator: &mut dyn Iterator<Item = NamedField<Self::InnerIteratorType>>) -> Result<Self, Vec<FieldError>>;
+}
+///The symmetric counterpart to [`SpecifyCreatableStruct`]: any type implementing this trait can be decomposed back into an [`Iterator`] over elements of type [`NamedField<I>`], where `I` is the same type assigned to [`InnerIteratorType`](SpecifyDeconstructableStruct::InnerIteratorType).
+///
+/// A type implementing both this trait and [`SpecifyCreatableStruct`] with the same `InnerIteratorType` can be decomposed with [`into_named_fields`](SpecifyDeconstructableStruct::into_named_fields) and rebuilt with [`create_struct`](SpecifyCreatableStruct::create_struct). Whether that round trip is lossless depends on the two implementations: neither trait ties the other's `InnerIteratorType` or behaviour to its own.
+pub trait SpecifyDeconstructableStruct: Sized {
+    ///The type contained in the [`NamedField`]s yielded by [`into_named_fields`](SpecifyDeconstructableStruct::into_named_fields) and [`named_fields`](SpecifyDeconstructableStruct::named_fields)
+    type InnerIteratorType;
+    ///Consumes `self`, yielding an [`Iterator`] over [`NamedField`]s holding each field's name and value.
+    fn into_named_fields(self) -> Box<dyn Iterator<Item = NamedField<Self::InnerIteratorType>>>;
+    ///Borrows `self`, yielding an [`Iterator`] over [`NamedField`]s holding each field's name and value.
+    fn named_fields(&self) -> Box<dyn Iterator<Item = NamedField<Self::InnerIteratorType>>>;
 }
\ No newline at end of file